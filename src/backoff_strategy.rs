@@ -0,0 +1,66 @@
+use crate::SleepDuration;
+use rand::thread_rng;
+use rand::Rng;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Jitter {
+    None,
+    Full,
+    Equal,
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum BackoffStrategy {
+    Fixed(SleepDuration),
+    Exponential {
+        base: Duration,
+        factor: f64,
+        max: Duration,
+        jitter: Jitter,
+    },
+}
+
+impl BackoffStrategy {
+    pub const fn fixed(sleep_duration: SleepDuration) -> Self {
+        Self::Fixed(sleep_duration)
+    }
+
+    pub const fn exponential(base: Duration, factor: f64, max: Duration, jitter: Jitter) -> Self {
+        Self::Exponential {
+            base,
+            factor,
+            max,
+            jitter,
+        }
+    }
+
+    pub fn sleep_duration_for_attempt(self, attempt: u32) -> Duration {
+        match self {
+            Self::Fixed(sleep_duration) => sleep_duration.into_duration(),
+            Self::Exponential {
+                base,
+                factor,
+                max,
+                jitter,
+            } => {
+                let raw = base.mul_f64(factor.powi(attempt as i32)).min(max);
+
+                match jitter {
+                    Jitter::None => raw,
+                    Jitter::Full => thread_rng().gen_range(Duration::ZERO..=raw),
+                    Jitter::Equal => {
+                        let half = raw / 2;
+                        half + thread_rng().gen_range(Duration::ZERO..=half)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl From<SleepDuration> for BackoffStrategy {
+    fn from(sleep_duration: SleepDuration) -> Self {
+        Self::Fixed(sleep_duration)
+    }
+}
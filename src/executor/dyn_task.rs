@@ -0,0 +1,18 @@
+use crate::Task;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Object-safe counterpart to [`Task`], so [`crate::ThrottledExecutor`] can
+/// hold a batch of differently-typed tasks behind one `Box<dyn DynTask<O>>`.
+pub(crate) trait DynTask<O>: Send {
+    fn call_boxed(&mut self) -> Pin<Box<dyn Future<Output = O> + '_>>;
+}
+
+impl<T, O> DynTask<O> for T
+where
+    T: Task<Output = O> + Send,
+{
+    fn call_boxed(&mut self) -> Pin<Box<dyn Future<Output = O> + '_>> {
+        Box::pin(self.call())
+    }
+}
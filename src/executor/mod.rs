@@ -0,0 +1,8 @@
+mod dyn_task;
+pub(crate) use self::dyn_task::*;
+
+mod task_handle;
+pub use self::task_handle::*;
+
+mod throttled_executor;
+pub use self::throttled_executor::*;
@@ -0,0 +1,23 @@
+use anyhow::anyhow;
+use anyhow::Result;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+/// Returned by [`crate::ThrottledExecutor::add`]; yields one output each
+/// time the executor completes a `call()` for that task.
+pub struct TaskHandle<O> {
+    receiver: UnboundedReceiver<O>,
+}
+
+impl<O> TaskHandle<O> {
+    pub(crate) fn new(receiver: UnboundedReceiver<O>) -> Self {
+        Self { receiver }
+    }
+
+    /// Waits for the next completed output from this task.
+    pub async fn recv(&mut self) -> Result<O> {
+        self.receiver
+            .recv()
+            .await
+            .ok_or_else(|| anyhow!("executor has stopped running this task"))
+    }
+}
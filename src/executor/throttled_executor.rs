@@ -0,0 +1,175 @@
+use crate::DynTask;
+use crate::SuccessTrackingTask;
+use crate::Task;
+use crate::TaskHandle;
+use anyhow::Result;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio::time::sleep_until;
+use tokio::time::Instant;
+
+struct Entry<O> {
+    task: Mutex<Box<dyn DynTask<O>>>,
+    sender: mpsc::UnboundedSender<O>,
+}
+
+/// Runs a batch of registered [`Task`]s on a single shared timer instead of
+/// one independent `sleep` per task. Every `throttle` window it polls every
+/// currently-registered task to completion of one `call()` each, then sleeps
+/// until the next window boundary.
+pub struct ThrottledExecutor<O> {
+    throttle: Duration,
+    success: SuccessTrackingTask,
+    tasks: Arc<Mutex<Vec<Entry<O>>>>,
+}
+
+impl<O> Clone for ThrottledExecutor<O> {
+    fn clone(&self) -> Self {
+        Self {
+            throttle: self.throttle,
+            success: self.success.clone(),
+            tasks: self.tasks.clone(),
+        }
+    }
+}
+
+impl<O> ThrottledExecutor<O> {
+    pub fn new(throttle: Duration) -> Self {
+        Self {
+            throttle,
+            success: SuccessTrackingTask::new(),
+            tasks: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    pub fn abort(&self) {
+        self.success.abort();
+    }
+
+    pub fn is_alive(&self) -> bool {
+        self.success.is_alive()
+    }
+
+    pub async fn add<T>(&self, task: T) -> TaskHandle<O>
+    where
+        T: Task<Output = O> + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        self.tasks.lock().await.push(Entry {
+            task: Mutex::new(Box::new(task)),
+            sender,
+        });
+
+        TaskHandle::new(receiver)
+    }
+
+    pub async fn run(&self) -> Result<()> {
+        let mut next_tick = Instant::now() + self.throttle;
+
+        while self.is_alive() {
+            let mut tasks = self.tasks.lock().await;
+            for entry in tasks.iter() {
+                let output = entry.task.lock().await.call_boxed().await;
+
+                // Ignore the error; it just means the handle was dropped.
+                let _ = entry.sender.send(output);
+            }
+
+            // Drop entries whose handle was dropped, so a forgotten
+            // `TaskHandle` doesn't keep its task running forever.
+            tasks.retain(|entry| !entry.sender.is_closed());
+            drop(tasks);
+
+            sleep_until(next_tick).await;
+            next_tick += self.throttle;
+        }
+
+        Ok(())
+    }
+
+    /// Runs this executor in a new blocking thread, for as long as it is
+    /// alive.
+    pub fn spawn(&self) -> JoinHandle<Result<()>>
+    where
+        O: Send + 'static,
+    {
+        let clone = self.clone();
+        tokio::task::spawn_blocking(move || {
+            tokio::runtime::Handle::current().block_on(async move { clone.run().await })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_run {
+    use super::*;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+
+    struct CountingTask {
+        counter: Arc<AtomicU32>,
+    }
+
+    impl Task for CountingTask {
+        type Output = u32;
+
+        async fn call(&mut self) -> u32 {
+            self.counter.fetch_add(1, Ordering::SeqCst) + 1
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_deliver_each_tick_through_the_handle() {
+        let executor = ThrottledExecutor::new(Duration::from_millis(20));
+        let counter = Arc::new(AtomicU32::new(0));
+        let mut handle = executor.add(CountingTask { counter }).await;
+
+        let join = executor.spawn();
+
+        assert_eq!(handle.recv().await.unwrap(), 1);
+        assert_eq!(handle.recv().await.unwrap(), 2);
+
+        executor.abort();
+        let _ = join.await;
+    }
+
+    #[tokio::test]
+    async fn it_should_poll_every_registered_task_each_window() {
+        let executor = ThrottledExecutor::new(Duration::from_millis(15));
+        let counter_a = Arc::new(AtomicU32::new(0));
+        let counter_b = Arc::new(AtomicU32::new(0));
+
+        let mut handle_a = executor.add(CountingTask { counter: counter_a }).await;
+        let mut handle_b = executor.add(CountingTask { counter: counter_b }).await;
+
+        let join = executor.spawn();
+
+        assert_eq!(handle_a.recv().await.unwrap(), 1);
+        assert_eq!(handle_b.recv().await.unwrap(), 1);
+
+        executor.abort();
+        let _ = join.await;
+    }
+
+    #[tokio::test]
+    async fn it_should_prune_tasks_once_their_handle_is_dropped() {
+        let executor = ThrottledExecutor::new(Duration::from_millis(10));
+        let counter = Arc::new(AtomicU32::new(0));
+
+        let handle = executor.add(CountingTask { counter }).await;
+        drop(handle);
+
+        assert_eq!(executor.tasks.lock().await.len(), 1);
+
+        let join = executor.spawn();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        executor.abort();
+        let _ = join.await;
+
+        assert_eq!(executor.tasks.lock().await.len(), 0);
+    }
+}
@@ -7,6 +7,9 @@ pub use self::retry_task::*;
 mod retry;
 pub use self::retry::*;
 
+mod backoff_strategy;
+pub use self::backoff_strategy::*;
+
 mod sleep_duration;
 pub use self::sleep_duration::*;
 
@@ -21,3 +24,18 @@ pub use self::task_ext::*;
 
 mod task_spawn_ext;
 pub use self::task_spawn_ext::*;
+
+mod worker;
+pub use self::worker::*;
+
+mod sleep_params;
+pub use self::sleep_params::*;
+
+mod work_outcome;
+pub use self::work_outcome::*;
+
+mod executor;
+pub use self::executor::*;
+
+mod timeout_task;
+pub use self::timeout_task::*;
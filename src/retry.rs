@@ -1,27 +1,58 @@
+use crate::BackoffStrategy;
 use crate::FnTask;
 use crate::RetryTask;
 use crate::SleepDuration;
 use crate::Task;
+use ::std::any::Any;
+use ::std::any::TypeId;
+use ::std::fmt;
 use ::std::future::Future;
+use ::std::sync::Arc;
+use ::std::time::Duration;
 
 const DEFAULT_NUM_RETRIES: u32 = 3;
 const DEFAULT_SLEEP_DURATION: SleepDuration = SleepDuration::from_millis(10_000);
+const DEFAULT_BACKOFF: BackoffStrategy = BackoffStrategy::Fixed(DEFAULT_SLEEP_DURATION);
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone)]
+struct RetryIf {
+    error_type: TypeId,
+    predicate: Arc<dyn Fn(&dyn Any) -> bool + Send + Sync>,
+}
+
+#[derive(Clone)]
 pub struct Retry {
     num_retries: u32,
-    sleep_duration: SleepDuration,
+    backoff: BackoffStrategy,
+    retry_if: Option<RetryIf>,
+    max_elapsed: Option<Duration>,
+}
+
+impl fmt::Debug for Retry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("num_retries", &self.num_retries)
+            .field("backoff", &self.backoff)
+            .field(
+                "retry_if",
+                &self.retry_if.as_ref().map(|_| "<predicate>"),
+            )
+            .field("max_elapsed", &self.max_elapsed)
+            .finish()
+    }
 }
 
 impl Retry {
     pub fn new() -> Self {
         Self {
             num_retries: DEFAULT_NUM_RETRIES,
-            sleep_duration: DEFAULT_SLEEP_DURATION,
+            backoff: DEFAULT_BACKOFF,
+            retry_if: None,
+            max_elapsed: None,
         }
     }
 
-    pub fn retries(self) -> u32 {
+    pub fn retries(&self) -> u32 {
         self.num_retries
     }
 
@@ -32,8 +63,12 @@ impl Retry {
         }
     }
 
-    pub fn sleep_duration(self) -> SleepDuration {
-        self.sleep_duration
+    pub fn backoff(&self) -> BackoffStrategy {
+        self.backoff
+    }
+
+    pub fn set_backoff(self, backoff: BackoffStrategy) -> Self {
+        Self { backoff, ..self }
     }
 
     pub fn set_sleep_duration<S>(self, sleep_duration: S) -> Self
@@ -41,15 +76,73 @@ impl Retry {
         S: Into<SleepDuration>,
     {
         Self {
-            sleep_duration: sleep_duration.into(),
+            backoff: BackoffStrategy::Fixed(sleep_duration.into()),
+            ..self
+        }
+    }
+
+    /// Only retries `Err(err)` for as long as `predicate(&err)` returns `true`.
+    /// Once it returns `false` the error is returned immediately, without
+    /// sleeping or consuming any further attempts.
+    pub fn set_retry_if<E, F>(self, predicate: F) -> Self
+    where
+        E: 'static,
+        F: Fn(&E) -> bool + Send + Sync + 'static,
+    {
+        Self {
+            retry_if: Some(RetryIf {
+                error_type: TypeId::of::<E>(),
+                predicate: Arc::new(move |err: &dyn Any| {
+                    predicate(err.downcast_ref::<E>().expect(
+                        "Retry::should_retry already checked the error's TypeId matches",
+                    ))
+                }),
+            }),
             ..self
         }
     }
 
+    /// Gives up once the total time spent retrying reaches `max_elapsed`,
+    /// regardless of the error. This is checked alongside any
+    /// [`Retry::set_retry_if`] predicate, and does not replace it.
+    pub fn set_retry_if_max_elapsed(self, max_elapsed: Duration) -> Self {
+        Self {
+            max_elapsed: Some(max_elapsed),
+            ..self
+        }
+    }
+
+    pub(crate) fn should_retry<E>(&self, err: &E, elapsed: Duration) -> bool
+    where
+        E: 'static,
+    {
+        if let Some(max_elapsed) = self.max_elapsed {
+            if elapsed >= max_elapsed {
+                return false;
+            }
+        }
+
+        match &self.retry_if {
+            None => true,
+            Some(retry_if) => {
+                assert_eq!(
+                    retry_if.error_type,
+                    TypeId::of::<E>(),
+                    "Retry::set_retry_if was registered for a different error type than the \
+                     task it is now retrying; a single `Retry` can only be reused across tasks \
+                     that share the same error type as the predicate"
+                );
+
+                (retry_if.predicate)(err)
+            }
+        }
+    }
+
     pub async fn run_fn<'a, T, F, O, E>(self, fn_task: T) -> Result<O, E>
     where
         T: FnMut() -> F,
         F: Future<Output = Result<O, E>>,
+        E: 'static,
     {
         let task = FnTask::new(fn_task);
         self.run(task).await
@@ -58,6 +151,7 @@ impl Retry {
     pub async fn run<'a, T, O, E>(self, task: T) -> Result<O, E>
     where
         T: Task<Output = Result<O, E>>,
+        E: 'static,
     {
         self.build_task(task).call().await
     }
@@ -65,6 +159,7 @@ impl Retry {
     pub fn build_task<'a, T, O, E>(self, task: T) -> RetryTask<T>
     where
         T: Task<Output = Result<O, E>>,
+        E: 'static,
     {
         RetryTask::new(task.into(), self)
     }
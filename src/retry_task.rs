@@ -1,9 +1,10 @@
+use std::time::Instant;
 use tokio::time::sleep;
 
 use crate::Retry;
 use crate::Task;
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct RetryTask<T>
 where
     T: Task,
@@ -24,6 +25,7 @@ where
 impl<T, O, E> Task for RetryTask<T>
 where
     T: Task<Output = Result<O, E>>,
+    E: 'static,
 {
     type Output = T::Output;
 
@@ -31,20 +33,25 @@ where
     where
         T: Task<Output = Result<O, E>>,
     {
-        let num_retries = self.retries.num_retries;
-        let sleep_time = self.retries.sleep_time;
+        let num_retries = self.retries.retries();
+        let backoff = self.retries.backoff();
+        let start = Instant::now();
 
         let mut last_error: Option<E> = None;
 
-        for retry in 0..num_retries {
+        for attempt in 0..num_retries {
             let result = self.task.call().await;
 
             match result {
                 Err(err) => {
+                    if !self.retries.should_retry(&err, start.elapsed()) {
+                        return Err(err);
+                    }
+
                     last_error = Some(err);
 
-                    if retry < num_retries {
-                        sleep(sleep_time.into_duration()).await;
+                    if attempt < num_retries {
+                        sleep(backoff.sleep_duration_for_attempt(attempt)).await;
                         continue;
                     }
                 }
@@ -65,7 +72,9 @@ where
 mod test_run {
     use super::*;
 
+    use crate::BackoffStrategy;
     use crate::FnTask;
+    use crate::Jitter;
     use crate::TaskExt;
     use anyhow::anyhow;
     use anyhow::bail;
@@ -94,8 +103,8 @@ mod test_run {
         let num_calls = AtomicU32::new(0);
 
         let retries = Retry::new()
-            .sleep_time(Duration::from_millis(0))
-            .retries(10);
+            .set_sleep_duration(Duration::from_millis(0))
+            .set_retries(10);
 
         let task = FnTask::new(|| async {
             let current_val = num_calls.fetch_add(1, Ordering::Acquire) + 1;
@@ -116,8 +125,8 @@ mod test_run {
         let num_calls = AtomicU32::new(0);
 
         let retries = Retry::new()
-            .sleep_time(Duration::from_millis(100))
-            .retries(10);
+            .set_sleep_duration(Duration::from_millis(100))
+            .set_retries(10);
 
         let task = FnTask::new(|| async {
             let current_val = num_calls.fetch_add(1, Ordering::Acquire) + 1;
@@ -142,8 +151,8 @@ mod test_run {
         let num_calls = AtomicU32::new(0);
 
         let retries = Retry::new()
-            .sleep_time(Duration::from_millis(0))
-            .retries(10);
+            .set_sleep_duration(Duration::from_millis(0))
+            .set_retries(10);
 
         let task = FnTask::new(|| async {
             num_calls.fetch_add(1, Ordering::Acquire);
@@ -156,4 +165,67 @@ mod test_run {
         assert!(result.is_err());
         assert_eq!(num_calls.into_inner(), 10);
     }
+
+    #[tokio::test]
+    async fn it_should_grow_the_sleep_between_attempts_exponentially() {
+        let num_calls = AtomicU32::new(0);
+
+        let retries = Retry::new().set_retries(10).set_backoff(BackoffStrategy::exponential(
+            Duration::from_millis(10),
+            2.0,
+            Duration::from_millis(1_000),
+            Jitter::None,
+        ));
+
+        let task = FnTask::new(|| async {
+            let current_val = num_calls.fetch_add(1, Ordering::Acquire) + 1;
+            if current_val < 4 {
+                bail!("not enough calls");
+            }
+
+            Ok(()) as Result<()>
+        });
+
+        let start = std::time::Instant::now();
+        let result = RetryTask::new(task, retries).call().await;
+        let end = std::time::Instant::now();
+        let time_taken = end - start;
+
+        // Attempts 0, 1, 2 sleep for 10ms, 20ms, 40ms respectively.
+        assert!(result.is_ok());
+        assert!(time_taken >= Duration::from_millis(70));
+    }
+
+    #[tokio::test]
+    async fn it_should_stop_retrying_once_retry_if_returns_false() {
+        let num_calls = AtomicU32::new(0);
+
+        let retries = Retry::new()
+            .set_sleep_duration(Duration::from_millis(0))
+            .set_retries(10)
+            .set_retry_if(|err: &anyhow::Error| err.to_string() != "permanent failure");
+
+        let task = FnTask::new(|| async {
+            num_calls.fetch_add(1, Ordering::Acquire);
+
+            Err(anyhow!("permanent failure")) as Result<()>
+        });
+
+        let result = task.with_retry(retries).call().await;
+
+        assert!(result.is_err());
+        assert_eq!(num_calls.into_inner(), 1);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "a different error type")]
+    async fn it_should_panic_if_retry_if_was_registered_for_a_different_error_type() {
+        let retries = Retry::new()
+            .set_sleep_duration(Duration::from_millis(0))
+            .set_retry_if(|_err: &std::io::Error| false);
+
+        let task = FnTask::new(|| async { Err(anyhow!("wrong error type")) as Result<()> });
+
+        let _ = task.with_retry(retries).call().await;
+    }
 }
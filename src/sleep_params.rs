@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+/// Idle-backoff schedule for [`crate::SuccessTrackingTask::while_alive_throttled`].
+///
+/// `current` is both the starting sleep and the value it resets to once work
+/// appears again; each consecutive idle poll grows it by `step`, up to `max`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SleepParams {
+    pub current: Duration,
+    pub step: Duration,
+    pub max: Duration,
+}
+
+impl SleepParams {
+    pub const fn new(current: Duration, step: Duration, max: Duration) -> Self {
+        Self { current, step, max }
+    }
+}
@@ -1,3 +1,5 @@
+use crate::SleepParams;
+use crate::WorkOutcome;
 use anyhow::anyhow;
 use anyhow::Error as AnyhowError;
 use anyhow::Result;
@@ -6,6 +8,7 @@ use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
 use tokio::task::JoinHandle;
+use tokio::time::sleep;
 
 #[derive(Debug, Clone)]
 pub struct SuccessTrackingTask {
@@ -80,6 +83,51 @@ impl SuccessTrackingTask {
         Ok(())
     }
 
+    /// Like [`Self::while_alive`], but for tasks that distinguish an idle
+    /// poll from one that did work. Each consecutive idle poll sleeps for
+    /// longer, up to `params.max`, so a task polling an empty queue doesn't
+    /// busy-loop; the sleep resets to `params.current` as soon as work
+    /// appears again.
+    pub async fn while_alive_throttled<T, E>(&self, mut task: T, params: SleepParams) -> Result<()>
+    where
+        T: crate::task::Task<Output = Result<WorkOutcome, E>>,
+        E: Into<AnyhowError>,
+    {
+        if !self.is_alive() {
+            return Err(anyhow!("calling run on a task that has already ended"));
+        }
+
+        let mut current = params.current;
+
+        while self.is_alive() {
+            let result = task.call().await.map_err(Into::into);
+
+            match result {
+                Err(err) => {
+                    if self.is_debug_print {
+                        eprintln!(
+                            "Success Tracking Error failed (while_alive_throttled) | {:?}",
+                            err
+                        )
+                    }
+
+                    self.abort();
+                    return Err(err);
+                }
+                Ok(WorkOutcome::Idle) => {
+                    sleep(current).await;
+                    current = (current + params.step).min(params.max);
+                }
+                Ok(WorkOutcome::Did) => {
+                    current = params.current;
+                }
+            }
+        }
+
+        // This will be returned if something else killed this.
+        Ok(())
+    }
+
     // Runs the given task in a new blocking thread, on it's own.
     //
     // It will spin there for as long as this is alive.
@@ -268,3 +316,107 @@ mod test_while_alive {
         assert!(result.is_err());
     }
 }
+
+#[cfg(test)]
+mod test_while_alive_throttled {
+    use super::*;
+
+    use crate::FnTask;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn it_should_abort_clones() {
+        let num_calls = Arc::new(AtomicU32::new(0));
+        let task = SuccessTrackingTask::new();
+        let clone = task.clone();
+
+        task.abort();
+        let result = clone
+            .while_alive_throttled(
+                FnTask::new(|| async {
+                    num_calls.fetch_add(1, Ordering::Acquire);
+
+                    Ok(WorkOutcome::Did) as Result<WorkOutcome>
+                }),
+                SleepParams::new(
+                    Duration::from_millis(0),
+                    Duration::from_millis(0),
+                    Duration::from_millis(0),
+                ),
+            )
+            .await;
+
+        assert!(result.is_err());
+        assert_eq!(num_calls.load(Ordering::Acquire), 0);
+    }
+
+    #[tokio::test]
+    async fn it_runs_loop_whilst_alive() {
+        let num_calls = Arc::new(AtomicU32::new(0));
+        let task = SuccessTrackingTask::new();
+
+        let result = task
+            .while_alive_throttled(
+                FnTask::new(|| async {
+                    let current_num = num_calls.fetch_add(1, Ordering::Acquire) + 1;
+                    if current_num >= 3 {
+                        return Err(anyhow!("Quit after 3 runs"));
+                    }
+
+                    Ok(WorkOutcome::Did) as Result<WorkOutcome>
+                }),
+                SleepParams::new(
+                    Duration::from_millis(0),
+                    Duration::from_millis(0),
+                    Duration::from_millis(0),
+                ),
+            )
+            .await;
+
+        assert_eq!(num_calls.load(Ordering::Acquire), 3);
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_should_grow_the_idle_sleep_and_reset_it_once_work_is_done() {
+        let num_calls = Arc::new(AtomicU32::new(0));
+        let task = SuccessTrackingTask::new();
+        let clone = task.clone();
+
+        let params = SleepParams::new(
+            Duration::from_millis(10),
+            Duration::from_millis(10),
+            Duration::from_millis(30),
+        );
+
+        let start = std::time::Instant::now();
+        let result = task
+            .while_alive_throttled(
+                // Borrows `clone` rather than cloning it per call, as dropping
+                // any `SuccessTrackingTask` clone aborts the loop.
+                FnTask::new(|| async {
+                    let current_num = num_calls.fetch_add(1, Ordering::Acquire) + 1;
+
+                    // Idle, idle, did (resets), idle, then abort (still idle).
+                    match current_num {
+                        1 | 2 | 4 => Ok(WorkOutcome::Idle) as Result<WorkOutcome>,
+                        3 => Ok(WorkOutcome::Did),
+                        _ => {
+                            clone.abort();
+                            Ok(WorkOutcome::Idle)
+                        }
+                    }
+                }),
+                params,
+            )
+            .await;
+        let time_taken = start.elapsed();
+
+        assert!(result.is_ok());
+        // Idle sleeps of 10ms, 20ms (grown), 10ms (reset after the Did), then
+        // 20ms after the aborting call = 60ms.
+        assert!(time_taken >= Duration::from_millis(60));
+    }
+}
@@ -1,9 +1,13 @@
 use crate::Retry;
 use crate::RetryTask;
 use crate::Task;
+use crate::TimeoutTask;
+use std::time::Duration;
 
 pub trait TaskExt: Task + Sized {
     fn with_retry(self, retries: Retry) -> RetryTask<Self>;
+
+    fn with_timeout(self, duration: Duration) -> TimeoutTask<Self>;
 }
 
 impl<T, O, E> TaskExt for T
@@ -13,4 +17,8 @@ where
     fn with_retry(self, retries: Retry) -> RetryTask<Self> {
         RetryTask::new(self, retries)
     }
+
+    fn with_timeout(self, duration: Duration) -> TimeoutTask<Self> {
+        TimeoutTask::new(self, duration)
+    }
 }
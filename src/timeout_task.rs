@@ -0,0 +1,88 @@
+use crate::Task;
+use std::time::Duration;
+use tokio::time::error::Elapsed;
+use tokio::time::timeout;
+
+#[derive(Copy, Clone, Debug)]
+pub struct TimeoutTask<T>
+where
+    T: Task,
+{
+    task: T,
+    duration: Duration,
+}
+
+impl<T> TimeoutTask<T>
+where
+    T: Task,
+{
+    pub fn new(task: T, duration: Duration) -> Self {
+        Self { task, duration }
+    }
+}
+
+impl<T, O, E> Task for TimeoutTask<T>
+where
+    T: Task<Output = Result<O, E>>,
+    E: From<Elapsed>,
+{
+    type Output = T::Output;
+
+    async fn call(&mut self) -> Result<O, E>
+    where
+        T: Task<Output = Result<O, E>>,
+    {
+        match timeout(self.duration, self.task.call()).await {
+            Ok(result) => result,
+            Err(elapsed) => Err(elapsed.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_run {
+    use super::*;
+
+    use crate::FnTask;
+    use crate::TaskExt;
+    use anyhow::anyhow;
+    use anyhow::Result;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::atomic::Ordering;
+
+    #[tokio::test]
+    async fn it_should_return_ok_if_within_the_timeout() {
+        let num_calls = AtomicU32::new(0);
+        let task = FnTask::new(|| async {
+            num_calls.fetch_add(1, Ordering::Acquire);
+            Ok(()) as Result<()>
+        });
+
+        let result = task.with_timeout(Duration::from_millis(100)).call().await;
+
+        assert!(result.is_ok());
+        assert_eq!(num_calls.into_inner(), 1);
+    }
+
+    #[tokio::test]
+    async fn it_should_return_err_if_the_task_never_completes_in_time() {
+        let task = FnTask::new(|| async {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            Ok(()) as Result<()>
+        });
+
+        let result = task.with_timeout(Duration::from_millis(10)).call().await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn it_should_pass_through_the_task_error_untouched() {
+        let task = FnTask::new(|| async { Err(anyhow!("boom")) as Result<()> });
+
+        let result = task.with_timeout(Duration::from_millis(100)).call().await;
+
+        assert!(result.is_err());
+        assert_eq!(result.unwrap_err().to_string(), "boom");
+    }
+}
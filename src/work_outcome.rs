@@ -0,0 +1,8 @@
+/// Distinguishes a poll that found work to do from one that found the queue
+/// empty, so [`crate::SuccessTrackingTask::while_alive_throttled`] knows
+/// whether to grow or reset its idle sleep.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum WorkOutcome {
+    Idle,
+    Did,
+}
@@ -0,0 +1,7 @@
+/// The result of running a single job pulled from a [`crate::TaskSource`],
+/// handed to a [`crate::TaskWorker`]'s `finalize` callback.
+#[derive(Debug)]
+pub enum JobOutcome<O, E> {
+    Succeeded(O),
+    Failed(E),
+}
@@ -0,0 +1,11 @@
+mod task_source;
+pub use self::task_source::*;
+
+mod retention_mode;
+pub use self::retention_mode::*;
+
+mod job_outcome;
+pub use self::job_outcome::*;
+
+mod task_worker;
+pub use self::task_worker::*;
@@ -0,0 +1,12 @@
+/// Controls which job outcomes a [`crate::TaskWorker`] reports back through
+/// its `finalize` callback.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RetentionMode {
+    /// Don't report any outcomes, succeeded or failed.
+    RemoveAll,
+    /// Only report outcomes for jobs whose final result was an error, whether
+    /// they ran out of retries or the `Retry` stopped retrying early.
+    KeepFailed,
+    /// Report every outcome, succeeded or failed.
+    KeepAll,
+}
@@ -0,0 +1,10 @@
+use crate::Task;
+use ::std::future::Future;
+
+/// A source of work for a [`crate::TaskWorker`] to pull from, such as a
+/// database queue or an in-memory channel.
+pub trait TaskSource {
+    type Job: Task;
+
+    fn next(&mut self) -> impl Future<Output = Option<Self::Job>>;
+}
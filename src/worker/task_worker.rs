@@ -0,0 +1,294 @@
+use crate::JobOutcome;
+use crate::RetentionMode;
+use crate::Retry;
+use crate::SleepDuration;
+use crate::SuccessTrackingTask;
+use crate::Task;
+use crate::TaskSource;
+use anyhow::Result;
+use tokio::task::JoinHandle;
+
+const DEFAULT_IDLE_SLEEP: SleepDuration = SleepDuration::from_millis(1_000);
+
+/// Continuously pulls jobs from a [`TaskSource`] and runs each one through
+/// `retries`, reporting outcomes back through `finalize` according to
+/// `retention`.
+pub struct TaskWorker<S, F>
+where
+    S: TaskSource,
+{
+    source: S,
+    retries: Retry,
+    success: SuccessTrackingTask,
+    retention: RetentionMode,
+    finalize: F,
+    idle_sleep: SleepDuration,
+}
+
+impl<S, F> TaskWorker<S, F>
+where
+    S: TaskSource,
+{
+    pub fn new(
+        source: S,
+        retries: Retry,
+        success: SuccessTrackingTask,
+        retention: RetentionMode,
+        finalize: F,
+    ) -> Self {
+        Self {
+            source,
+            retries,
+            success,
+            retention,
+            finalize,
+            idle_sleep: DEFAULT_IDLE_SLEEP,
+        }
+    }
+
+    pub fn set_idle_sleep<D>(self, idle_sleep: D) -> Self
+    where
+        D: Into<SleepDuration>,
+    {
+        Self {
+            idle_sleep: idle_sleep.into(),
+            ..self
+        }
+    }
+}
+
+impl<S, F, O, E> TaskWorker<S, F>
+where
+    S: TaskSource,
+    S::Job: Task<Output = Result<O, E>>,
+    E: 'static,
+    F: FnMut(JobOutcome<O, E>),
+{
+    pub async fn run(&mut self) -> Result<()> {
+        while self.success.is_alive() {
+            match self.source.next().await {
+                None => {
+                    self.idle_sleep.sleep().await;
+                    continue;
+                }
+                Some(job) => {
+                    let result = self.retries.clone().build_task(job).call().await;
+
+                    let should_finalize = match self.retention {
+                        RetentionMode::RemoveAll => false,
+                        RetentionMode::KeepFailed => result.is_err(),
+                        RetentionMode::KeepAll => true,
+                    };
+
+                    if should_finalize {
+                        let outcome = match result {
+                            Ok(value) => JobOutcome::Succeeded(value),
+                            Err(err) => JobOutcome::Failed(err),
+                        };
+
+                        (self.finalize)(outcome);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Runs this worker in a new blocking thread, for as long as its
+    /// `SuccessTrackingTask` is alive.
+    pub fn spawn(mut self) -> JoinHandle<Result<()>>
+    where
+        S: Send + 'static,
+        S::Job: Send,
+        O: Send + 'static,
+        E: Send + 'static,
+        F: Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            tokio::runtime::Handle::current().block_on(async move { self.run().await })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_run {
+    use super::*;
+
+    use anyhow::anyhow;
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    struct TestJob {
+        should_fail: bool,
+    }
+
+    impl Task for TestJob {
+        type Output = Result<u32>;
+
+        async fn call(&mut self) -> Result<u32> {
+            if self.should_fail {
+                Err(anyhow!("job failed"))
+            } else {
+                Ok(1)
+            }
+        }
+    }
+
+    struct TestSource {
+        jobs: VecDeque<TestJob>,
+    }
+
+    impl TaskSource for TestSource {
+        type Job = TestJob;
+
+        async fn next(&mut self) -> Option<TestJob> {
+            self.jobs.pop_front()
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_idle_sleep_on_none_and_then_stop_when_aborted() {
+        let success = SuccessTrackingTask::new();
+        let success_clone = success.clone();
+
+        let source = TestSource {
+            jobs: VecDeque::new(),
+        };
+
+        let mut worker = TaskWorker::new(
+            source,
+            Retry::new(),
+            success,
+            RetentionMode::RemoveAll,
+            |_: JobOutcome<u32, anyhow::Error>| {},
+        )
+        .set_idle_sleep(Duration::from_millis(10));
+
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(25)).await;
+            success_clone.abort();
+        });
+
+        let start = std::time::Instant::now();
+        let result = worker.run().await;
+        let time_taken = start.elapsed();
+
+        assert!(result.is_ok());
+        assert!(time_taken >= Duration::from_millis(20));
+    }
+
+    #[tokio::test]
+    async fn it_should_report_nothing_on_remove_all() {
+        let success = SuccessTrackingTask::new();
+        let success_clone = success.clone();
+        let outcomes: Arc<Mutex<Vec<JobOutcome<u32, anyhow::Error>>>> =
+            Arc::new(Mutex::new(Vec::new()));
+        let outcomes_clone = outcomes.clone();
+
+        let source = TestSource {
+            jobs: VecDeque::from([
+                TestJob { should_fail: false },
+                TestJob { should_fail: true },
+            ]),
+        };
+
+        let mut worker = TaskWorker::new(
+            source,
+            Retry::new().set_sleep_duration(Duration::from_millis(0)),
+            success,
+            RetentionMode::RemoveAll,
+            move |outcome: JobOutcome<u32, anyhow::Error>| {
+                outcomes_clone.lock().unwrap().push(outcome);
+            },
+        )
+        .set_idle_sleep(Duration::from_millis(10));
+
+        // RemoveAll never calls finalize, so the only way to end the loop
+        // is to abort it once we're confident both jobs have drained.
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            success_clone.abort();
+        });
+
+        let result = worker.run().await;
+
+        assert!(result.is_ok());
+        assert!(outcomes.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_only_report_failures_on_keep_failed() {
+        let success = SuccessTrackingTask::new();
+        let outcomes: Arc<Mutex<Vec<JobOutcome<u32, anyhow::Error>>>> =
+            Arc::new(Mutex::new(Vec::new()));
+        let outcomes_clone = outcomes.clone();
+        let success_clone = success.clone();
+
+        let source = TestSource {
+            jobs: VecDeque::from([
+                TestJob { should_fail: false },
+                TestJob { should_fail: true },
+            ]),
+        };
+
+        let mut worker = TaskWorker::new(
+            source,
+            Retry::new().set_sleep_duration(Duration::from_millis(0)),
+            success,
+            RetentionMode::KeepFailed,
+            move |outcome: JobOutcome<u32, anyhow::Error>| {
+                outcomes_clone.lock().unwrap().push(outcome);
+                success_clone.abort();
+            },
+        )
+        .set_idle_sleep(Duration::from_millis(0));
+
+        let result = worker.run().await;
+
+        assert!(result.is_ok());
+        let outcomes = outcomes.lock().unwrap();
+        assert_eq!(outcomes.len(), 1);
+        assert!(matches!(outcomes[0], JobOutcome::Failed(_)));
+    }
+
+    #[tokio::test]
+    async fn it_should_report_everything_on_keep_all() {
+        let success = SuccessTrackingTask::new();
+        let outcomes: Arc<Mutex<Vec<JobOutcome<u32, anyhow::Error>>>> =
+            Arc::new(Mutex::new(Vec::new()));
+        let outcomes_clone = outcomes.clone();
+        let success_clone = success.clone();
+
+        let source = TestSource {
+            jobs: VecDeque::from([
+                TestJob { should_fail: false },
+                TestJob { should_fail: true },
+            ]),
+        };
+
+        let mut worker = TaskWorker::new(
+            source,
+            Retry::new().set_sleep_duration(Duration::from_millis(0)),
+            success,
+            RetentionMode::KeepAll,
+            move |outcome: JobOutcome<u32, anyhow::Error>| {
+                outcomes_clone.lock().unwrap().push(outcome);
+                if outcomes_clone.lock().unwrap().len() >= 2 {
+                    success_clone.abort();
+                }
+            },
+        )
+        .set_idle_sleep(Duration::from_millis(0));
+
+        let result = worker.run().await;
+
+        assert!(result.is_ok());
+        let outcomes = outcomes.lock().unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(matches!(outcomes[0], JobOutcome::Succeeded(1)));
+        assert!(matches!(outcomes[1], JobOutcome::Failed(_)));
+    }
+}